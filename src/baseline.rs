@@ -0,0 +1,130 @@
+//! Saving and comparing benchmark results against a named baseline.
+//!
+//! This mirrors Criterion's baseline workflow: a run with `--save-baseline
+//! NAME` serializes each benchmark's timing to disk under `--baseline-dir`,
+//! and a later run with `--baseline NAME` loads those numbers back and
+//! reports the delta alongside the current measurement. This lets CI gate
+//! performance by diffing against a committed baseline file.
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Threshold (as a fraction, e.g. `0.05` for 5%) beyond which a delta is
+/// classified as an improvement or regression rather than noise.
+pub(crate) const DEFAULT_NOISE_THRESHOLD: f64 = 0.05;
+
+/// Default directory baselines are saved to and loaded from:
+/// `target/divan/baselines`, relative to the current working directory.
+pub(crate) fn default_baseline_dir() -> PathBuf {
+    PathBuf::from("target/divan/baselines")
+}
+
+/// A named snapshot of benchmark timings, keyed by benchmark name.
+#[derive(Default)]
+pub(crate) struct Baseline {
+    medians_ns: HashMap<String, f64>,
+}
+
+impl Baseline {
+    /// Loads a previously saved baseline from `<dir>/<name>.txt`.
+    pub fn load(dir: &Path, name: &str) -> io::Result<Self> {
+        let contents = fs::read_to_string(dir.join(format!("{name}.txt")))?;
+        let mut medians_ns = HashMap::new();
+
+        for line in contents.lines() {
+            let Some((name, median_ns)) = line.rsplit_once('\t') else { continue };
+
+            if let Ok(median_ns) = median_ns.trim().parse() {
+                medians_ns.insert(name.to_owned(), median_ns);
+            }
+        }
+
+        Ok(Self { medians_ns })
+    }
+
+    /// Looks up the previously recorded median time, in nanoseconds, for a
+    /// benchmark by name.
+    pub fn median_ns(&self, name: &str) -> Option<f64> {
+        self.medians_ns.get(name).copied()
+    }
+}
+
+/// Accumulates per-benchmark medians over the course of a run, to be written
+/// out as a new baseline once the run completes.
+#[derive(Default)]
+pub(crate) struct BaselineWriter {
+    medians_ns: Vec<(String, f64)>,
+}
+
+impl BaselineWriter {
+    pub fn record(&mut self, name: &str, median_ns: f64) {
+        self.medians_ns.push((name.to_owned(), median_ns));
+    }
+
+    /// Saves the accumulated medians to `<dir>/<name>.txt`, creating `dir` if
+    /// it doesn't already exist.
+    pub fn save(&self, dir: &Path, name: &str) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+
+        let mut contents = String::new();
+        for (name, median_ns) in &self.medians_ns {
+            contents.push_str(name);
+            contents.push('\t');
+            contents.push_str(&median_ns.to_string());
+            contents.push('\n');
+        }
+
+        fs::write(dir.join(format!("{name}.txt")), contents)
+    }
+}
+
+/// The outcome of comparing a current measurement against a baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum BaselineChange {
+    /// The delta is within the noise threshold.
+    NoChange,
+
+    /// The current measurement is faster than the baseline by more than the
+    /// noise threshold.
+    Improvement,
+
+    /// The current measurement is slower than the baseline by more than the
+    /// noise threshold.
+    Regression,
+}
+
+/// The result of comparing a current median against a baseline median.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BaselineDelta {
+    /// Relative change, e.g. `0.042` for a 4.2% slowdown.
+    pub fraction: f64,
+
+    pub change: BaselineChange,
+}
+
+/// Compares a current median against a previously recorded baseline median,
+/// classifying the result using `threshold` (a fraction, e.g. `0.05`).
+///
+/// A recorded baseline of `0` ns can't be used as a relative-change
+/// denominator, so that case is classified as [`BaselineChange::NoChange`]
+/// instead of dividing by zero into `inf`/`NaN`.
+pub(crate) fn compare(baseline_ns: f64, current_ns: f64, threshold: f64) -> BaselineDelta {
+    if baseline_ns == 0.0 {
+        return BaselineDelta { fraction: 0.0, change: BaselineChange::NoChange };
+    }
+
+    let fraction = (current_ns - baseline_ns) / baseline_ns;
+
+    let change = if fraction > threshold {
+        BaselineChange::Regression
+    } else if fraction < -threshold {
+        BaselineChange::Improvement
+    } else {
+        BaselineChange::NoChange
+    };
+
+    BaselineDelta { fraction, change }
+}
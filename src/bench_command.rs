@@ -0,0 +1,131 @@
+//! Benchmarking external commands, for timing non-Rust workloads alongside
+//! in-process benchmarks.
+//!
+//! Registered via [`Bencher::bench_command`](crate::Bencher::bench_command),
+//! this drives a [`std::process::Command`] through the normal
+//! `--sample-count`/`--sample-size` loop, measuring each invocation's
+//! wall-clock duration the same way Criterion benchmarks external programs.
+
+use std::{
+    fmt, io,
+    process::{Command, ExitStatus, Stdio},
+    time::{Duration, Instant},
+};
+
+/// An error encountered while timing a benchmarked command.
+#[derive(Debug)]
+pub enum CommandBenchError {
+    /// The command couldn't be spawned at all.
+    Spawn(io::Error),
+
+    /// The command ran but exited with a non-zero (or signal-terminated)
+    /// status.
+    ExitStatus(ExitStatus),
+}
+
+impl fmt::Display for CommandBenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn(err) => write!(f, "failed to spawn benchmarked command: {err}"),
+            Self::ExitStatus(status) => write!(f, "benchmarked command exited with {status}"),
+        }
+    }
+}
+
+impl std::error::Error for CommandBenchError {}
+
+/// A benchmarked external command, built from a [`Command`].
+///
+/// Each sample spawns a fresh process and waits for it to exit; stdio is
+/// always discarded so that the measured interval isn't skewed by the
+/// terminal or pipe buffering.
+pub(crate) struct CommandBencher {
+    command: Command,
+}
+
+impl CommandBencher {
+    /// Wraps `command` for timing. Its stdin/stdout/stderr are redirected to
+    /// null sinks, replacing any stdio already configured on it.
+    pub fn new(mut command: Command) -> Self {
+        command.stdin(Stdio::null());
+        command.stdout(Stdio::null());
+        command.stderr(Stdio::null());
+
+        Self { command }
+    }
+
+    /// Runs the command once, returning the wall-clock duration of the
+    /// spawn-to-exit interval.
+    ///
+    /// When `skip_spawn_overhead` is set, the returned duration excludes an
+    /// estimate of the time spent spawning and tearing down the process
+    /// (measured via a throwaway no-op spawn beforehand), so `--skip-ext-time`
+    /// reflects the command's own execution time as closely as the OS allows.
+    ///
+    /// Returns [`CommandBenchError`] rather than panicking if the command
+    /// can't be spawned or exits with a non-zero status, since either is a
+    /// reachable outcome for an arbitrary external program.
+    pub fn time_once(&mut self, skip_spawn_overhead: bool) -> Result<Duration, CommandBenchError> {
+        let spawn_overhead =
+            if skip_spawn_overhead { self.measure_spawn_overhead() } else { Duration::ZERO };
+
+        let start = Instant::now();
+        let status = self.command.status().map_err(CommandBenchError::Spawn)?;
+        let elapsed = start.elapsed();
+
+        if !status.success() {
+            return Err(CommandBenchError::ExitStatus(status));
+        }
+
+        Ok(elapsed.saturating_sub(spawn_overhead))
+    }
+
+    /// Approximates process spawn/teardown overhead by running a trivial
+    /// no-op command and timing it. Best-effort: if the no-op can't be
+    /// spawned, no overhead is subtracted rather than failing the benchmark.
+    fn measure_spawn_overhead(&self) -> Duration {
+        let mut noop = Command::new(if cfg!(windows) { "cmd" } else { "true" });
+        if cfg!(windows) {
+            noop.args(["/C", "exit 0"]);
+        }
+        noop.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+
+        let start = Instant::now();
+        let spawned = noop.status();
+        let elapsed = start.elapsed();
+
+        if spawned.is_ok() {
+            elapsed
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times_a_trivial_command() {
+        let mut bencher = CommandBencher::new(Command::new(if cfg!(windows) { "cmd" } else { "true" }));
+        bencher.time_once(false).expect("trivial command should succeed");
+    }
+
+    #[test]
+    fn reports_error_instead_of_panicking_on_missing_command() {
+        let mut bencher = CommandBencher::new(Command::new("divan-bench-command-does-not-exist"));
+        assert!(matches!(bencher.time_once(false), Err(CommandBenchError::Spawn(_))));
+    }
+
+    #[test]
+    fn reports_error_instead_of_panicking_on_nonzero_exit() {
+        let mut command = Command::new(if cfg!(windows) { "cmd" } else { "false" });
+        if cfg!(windows) {
+            command.args(["/C", "exit 1"]);
+        }
+
+        let mut bencher = CommandBencher::new(command);
+        assert!(matches!(bencher.time_once(false), Err(CommandBenchError::ExitStatus(_))));
+    }
+}
@@ -0,0 +1,137 @@
+//! The benchmarking context passed to a `#[divan::bench]` function.
+
+use std::{process::Command, time::Instant};
+
+use crate::{
+    bench_command::{CommandBenchError, CommandBencher},
+    counter::{AnyCounter, Counter, CounterCollection, KnownCounterKind},
+    time::{self, read_tsc, TimerKind},
+};
+
+/// What a [`Bencher`] measures: either a Rust closure or an external
+/// command, registered via [`Bencher::bench`] / [`Bencher::bench_command`].
+enum BenchTarget<'a> {
+    Closure(Box<dyn FnMut() + 'a>),
+    Command(CommandBencher),
+}
+
+/// A single sample's measurement: wall-clock time, plus a cycle count that
+/// is read directly from the TSC when [`TimerKind::Tsc`] is active, or
+/// converted from the wall time via the estimated TSC frequency when a
+/// [`CyclesCount`](crate::counter::CyclesCount) counter is set.
+pub(crate) struct SampleTiming {
+    pub duration_ns: f64,
+    pub cycles: Option<u64>,
+}
+
+/// Per-benchmark context, providing throughput counters and the means to
+/// register what gets measured.
+///
+/// A `Bencher` is handed to the function under a `#[divan::bench]`
+/// attribute; calling [`Bencher::bench`] or [`Bencher::bench_command`]
+/// registers the benchmark target that the sample/iteration loop (driven by
+/// `--sample-count`/`--sample-size`) then runs.
+pub struct Bencher<'a> {
+    counters: CounterCollection,
+    timer: TimerKind,
+    target: Option<BenchTarget<'a>>,
+    tsc_frequency_cache: Option<Option<f64>>,
+}
+
+impl<'a> Bencher<'a> {
+    pub(crate) fn new(timer: TimerKind) -> Self {
+        Self { counters: CounterCollection::default(), timer, target: None, tsc_frequency_cache: None }
+    }
+
+    /// Sets a throughput counter for this benchmark, e.g.
+    /// [`BytesCount`](crate::counter::BytesCount) or
+    /// [`CyclesCount`](crate::counter::CyclesCount).
+    ///
+    /// [`CyclesCount`](crate::counter::CyclesCount) reads the TSC directly
+    /// around the benchmarked closure when `--timer tsc` is active; when the
+    /// `os` timer is active instead, it falls back to converting the
+    /// measured wall time into cycles via an estimated TSC frequency, so the
+    /// `cycles/op` figure is still reported, just frequency-estimate-derived
+    /// rather than a direct TSC read.
+    pub fn counter<C: Counter>(mut self, counter: C) -> Self {
+        self.counters.insert(AnyCounter::new(counter));
+        self
+    }
+
+    /// Registers `f` as the benchmarked closure.
+    pub fn bench<O>(mut self, f: impl FnMut() -> O + 'a) {
+        let mut f = f;
+        self.target = Some(BenchTarget::Closure(Box::new(move || {
+            let _ = f();
+        })));
+    }
+
+    /// Registers `command` as the benchmarked external program.
+    ///
+    /// Each sample spawns a fresh process and waits for it to exit, driven
+    /// by the same `--sample-count`/`--sample-size` loop as closure
+    /// benchmarks.
+    pub fn bench_command(mut self, command: Command) {
+        self.target = Some(BenchTarget::Command(CommandBencher::new(command)));
+    }
+
+    pub(crate) fn has_cycles_counter(&self) -> bool {
+        self.counters.get(KnownCounterKind::Cycles).is_some()
+    }
+
+    /// Runs the registered target for `sample_size` iterations and returns
+    /// the measured sample.
+    ///
+    /// Wall time is always measured with [`Instant::now`]. When the TSC timer
+    /// is active, this also reads the timestamp counter immediately before
+    /// and after the iterations, so
+    /// [`CyclesCount`](crate::counter::CyclesCount) can report a
+    /// frequency-independent `cycles/op` figure alongside wall time.
+    pub(crate) fn run_sample(
+        &mut self,
+        sample_size: u32,
+        skip_ext_time: bool,
+    ) -> Result<SampleTiming, CommandBenchError> {
+        let target = self.target.as_mut().expect("no benchmark target registered");
+
+        let use_tsc = self.timer == TimerKind::Tsc;
+        let start_cycles = use_tsc.then(read_tsc).flatten();
+
+        let duration_ns = match target {
+            BenchTarget::Closure(f) => {
+                let start = Instant::now();
+                for _ in 0..sample_size {
+                    f();
+                }
+                start.elapsed().as_nanos() as f64
+            }
+            BenchTarget::Command(bencher) => {
+                let mut total = 0.0;
+                for _ in 0..sample_size {
+                    total += bencher.time_once(skip_ext_time)?.as_nanos() as f64;
+                }
+                total
+            }
+        };
+
+        let cycles = if let Some(start) = start_cycles {
+            read_tsc().map(|end| end - start)
+        } else if self.has_cycles_counter() {
+            // No direct TSC read (the `os` timer is active), but a
+            // `CyclesCount` counter was requested anyway: fall back to
+            // converting the measured wall time via the estimated TSC
+            // frequency, rather than silently dropping the counter.
+            self.tsc_frequency().map(|frequency_hz| time::ns_to_cycles(duration_ns, frequency_hz))
+        } else {
+            None
+        };
+
+        Ok(SampleTiming { duration_ns, cycles })
+    }
+
+    /// Lazily estimates and caches the TSC frequency for this `Bencher`'s
+    /// lifetime, so repeated samples don't each pay the calibration cost.
+    fn tsc_frequency(&mut self) -> Option<f64> {
+        *self.tsc_frequency_cache.get_or_insert_with(time::estimate_tsc_frequency)
+    }
+}
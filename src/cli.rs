@@ -2,6 +2,7 @@ use clap::{builder::PossibleValue, value_parser, Arg, ArgAction, ColorChoice, Co
 
 use crate::{
     config::{FormatStyle, ParsedSeconds, SortingAttr},
+    stats::DEFAULT_RESAMPLES,
     time::TimerKind,
 };
 
@@ -47,7 +48,7 @@ pub(crate) fn command() -> Command {
         .arg(
             option("format")
                 .help("Configure formatting of output")
-                .value_name("pretty|terse")
+                .value_name("pretty|terse|json")
                 .value_parser(value_parser!(FormatStyle))
                 .default_value("pretty"),
         )
@@ -123,6 +124,36 @@ pub(crate) fn command() -> Command {
                 .value_parser(value_parser!(bool))
                 .num_args(0..=1),
         )
+        .arg(flag("exclude-outliers").help("Exclude mild and severe outliers from the reported estimate"))
+        .arg(
+            option("bootstrap-resamples")
+                .env("DIVAN_BOOTSTRAP_RESAMPLES")
+                .value_name("N")
+                .help("Set the number of bootstrap resamples used to compute confidence intervals")
+                .value_parser(value_parser!(u32))
+                .default_value(DEFAULT_RESAMPLES.to_string()),
+        )
+        .arg(
+            option("save-baseline")
+                .env("DIVAN_SAVE_BASELINE")
+                .value_name("NAME")
+                .help("Save benchmark results under this baseline name")
+                .conflicts_with("baseline"),
+        )
+        .arg(
+            option("baseline")
+                .env("DIVAN_BASELINE")
+                .value_name("NAME")
+                .help("Compare benchmark results against this previously-saved baseline")
+                .conflicts_with("save-baseline"),
+        )
+        .arg(
+            option("baseline-dir")
+                .env("DIVAN_BASELINE_DIR")
+                .value_name("PATH")
+                .help("Set the directory baselines are saved to and loaded from")
+                .value_parser(value_parser!(std::path::PathBuf)),
+        )
         // ignored:
         .args([ignored_flag("bench"), ignored_flag("nocapture"), ignored_flag("show-output")])
 }
@@ -143,13 +174,14 @@ impl ValueEnum for TimerKind {
 
 impl ValueEnum for FormatStyle {
     fn value_variants<'a>() -> &'a [Self] {
-        &[Self::Pretty, Self::Terse]
+        &[Self::Pretty, Self::Terse, Self::Json]
     }
 
     fn to_possible_value(&self) -> Option<PossibleValue> {
         let name = match self {
             Self::Pretty => "pretty",
             Self::Terse => "terse",
+            Self::Json => "json",
         };
         Some(PossibleValue::new(name))
     }
@@ -0,0 +1,59 @@
+//! Global configuration for a benchmark run, parsed from CLI arguments and
+//! environment variables. See [`crate::cli::command`].
+
+use std::{fmt, str::FromStr, time::Duration};
+
+/// Controls how benchmark results are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FormatStyle {
+    /// Human-readable table, grouped by benchmark hierarchy.
+    #[default]
+    Pretty,
+
+    /// Condensed single-line-per-benchmark output.
+    Terse,
+
+    /// Line-delimited JSON, one event object per line. See
+    /// [`crate::format::json`].
+    Json,
+}
+
+/// Attribute benchmarks are sorted by, set via `--sort`/`--sortr`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortingAttr {
+    #[default]
+    Kind,
+    Name,
+    Location,
+}
+
+/// A non-negative duration parsed from a `SECS` CLI value, e.g.
+/// `--min-time 0.5`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParsedSeconds(pub Duration);
+
+impl FromStr for ParsedSeconds {
+    type Err = ParseSecondsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let secs: f64 = s.parse().map_err(|_| ParseSecondsError)?;
+
+        if !secs.is_finite() || secs < 0.0 {
+            return Err(ParseSecondsError);
+        }
+
+        Ok(Self(Duration::from_secs_f64(secs)))
+    }
+}
+
+/// Error returned when a `SECS` CLI value isn't a valid non-negative number.
+#[derive(Clone, Copy, Debug)]
+pub struct ParseSecondsError;
+
+impl fmt::Display for ParseSecondsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a non-negative number of seconds")
+    }
+}
+
+impl std::error::Error for ParseSecondsError {}
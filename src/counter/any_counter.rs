@@ -0,0 +1,60 @@
+use std::any::Any;
+
+use super::{uint::MaxCountUInt, BytesCount, CharsCount, Counter, CyclesCount, ItemsCount};
+
+/// Identifies which concrete counter kind an [`AnyCounter`] holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum KnownCounterKind {
+    Bytes,
+    Chars,
+    Items,
+    Cycles,
+}
+
+impl KnownCounterKind {
+    /// Number of counter kinds Divan ships.
+    pub const COUNT: usize = 4;
+
+    /// Short unit label used in the formatter's throughput column, e.g.
+    /// `cycles/op` for [`Self::Cycles`].
+    pub fn throughput_unit(self) -> &'static str {
+        match self {
+            Self::Bytes => "B/s",
+            Self::Chars => "char/s",
+            Self::Items => "item/s",
+            Self::Cycles => "cycles/op",
+        }
+    }
+}
+
+/// A type-erased [`Counter`], identified by [`KnownCounterKind`].
+///
+/// This lets [`CounterCollection`](super::CounterCollection) store any of
+/// Divan's counter types uniformly without a generic parameter.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AnyCounter {
+    pub kind: KnownCounterKind,
+    pub count: MaxCountUInt,
+}
+
+impl AnyCounter {
+    /// Type-erases `counter` into its known kind.
+    ///
+    /// `Counter` is sealed, so every implementor is one of the variants
+    /// handled below.
+    pub fn new<C: Counter>(counter: C) -> Self {
+        let any = &counter as &dyn Any;
+
+        if let Some(counter) = any.downcast_ref::<BytesCount>() {
+            Self { kind: KnownCounterKind::Bytes, count: counter.count }
+        } else if let Some(counter) = any.downcast_ref::<CharsCount>() {
+            Self { kind: KnownCounterKind::Chars, count: counter.count }
+        } else if let Some(counter) = any.downcast_ref::<ItemsCount>() {
+            Self { kind: KnownCounterKind::Items, count: counter.count }
+        } else if let Some(counter) = any.downcast_ref::<CyclesCount>() {
+            Self { kind: KnownCounterKind::Cycles, count: counter.count }
+        } else {
+            unreachable!("`Counter` is sealed to the kinds handled above")
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::any_counter::{AnyCounter, KnownCounterKind};
+
+/// A fixed-size collection of type-erased counters, indexed by
+/// [`KnownCounterKind`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CounterCollection {
+    counters: [Option<AnyCounter>; KnownCounterKind::COUNT],
+}
+
+impl Default for CounterCollection {
+    fn default() -> Self {
+        Self { counters: [None; KnownCounterKind::COUNT] }
+    }
+}
+
+impl CounterCollection {
+    pub fn insert(&mut self, counter: AnyCounter) {
+        self.counters[counter.kind as usize] = Some(counter);
+    }
+
+    pub fn get(&self, kind: KnownCounterKind) -> Option<AnyCounter> {
+        self.counters[kind as usize]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = AnyCounter> + '_ {
+        self.counters.iter().filter_map(|counter| *counter)
+    }
+}
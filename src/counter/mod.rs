@@ -29,17 +29,15 @@ use std::{any::Any, mem};
 
 mod any_counter;
 mod collection;
-mod into_counter;
 mod sealed;
 mod uint;
 
 pub(crate) use self::{
     any_counter::{AnyCounter, KnownCounterKind},
-    collection::{CounterCollection, CounterSet},
+    collection::CounterCollection,
     sealed::Sealed,
     uint::{CountUInt, MaxCountUInt},
 };
-pub use into_counter::IntoCounter;
 
 /// Counts the number of values processed in each iteration of a benchmarked
 /// function.
@@ -74,13 +72,27 @@ pub struct ItemsCount {
     count: MaxCountUInt,
 }
 
+/// Count N CPU cycles, measured via the timestamp counter.
+///
+/// With [`--timer tsc`](crate::time::TimerKind::Tsc) the cycles are read
+/// directly from the TSC around each sample; with the `os` timer they are
+/// converted from the measured wall time via an estimated TSC frequency.
+/// Only cycles per iteration (`cycles/op`) are reported; cycles per byte or
+/// per item are not.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CyclesCount {
+    count: MaxCountUInt,
+}
+
 impl Sealed for BytesCount {}
 impl Sealed for CharsCount {}
 impl Sealed for ItemsCount {}
+impl Sealed for CyclesCount {}
 
 impl Counter for BytesCount {}
 impl Counter for CharsCount {}
 impl Counter for ItemsCount {}
+impl Counter for CyclesCount {}
 
 impl BytesCount {
     /// Count N bytes.
@@ -158,6 +170,14 @@ impl ItemsCount {
     }
 }
 
+impl CyclesCount {
+    /// Count N CPU cycles.
+    #[inline]
+    pub fn new<N: CountUInt>(count: N) -> Self {
+        Self { count: count.into_max_uint() }
+    }
+}
+
 /// The numerical base for [`BytesCount`] in benchmark outputs.
 ///
 /// See [`Divan::bytes_format`](crate::Divan::bytes_format) for more info.
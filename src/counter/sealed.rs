@@ -0,0 +1,6 @@
+/// Prevents external implementations of [`Counter`](super::Counter).
+///
+/// `pub` (not `pub(crate)`) so it can appear as a supertrait bound on the
+/// public `Counter` trait without a private-in-public error; it stays
+/// unreachable from outside the crate because this module is private.
+pub trait Sealed {}
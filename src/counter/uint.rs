@@ -0,0 +1,23 @@
+/// The widest unsigned integer type used to store a counter's count.
+pub(crate) type MaxCountUInt = u128;
+
+/// Integer types that can be converted into a counter's stored count.
+pub trait CountUInt: Copy {
+    /// Converts `self` into the widest counter integer type.
+    fn into_max_uint(self) -> MaxCountUInt;
+}
+
+macro_rules! impl_count_uint {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl CountUInt for $ty {
+                #[inline]
+                fn into_max_uint(self) -> MaxCountUInt {
+                    self as MaxCountUInt
+                }
+            }
+        )*
+    };
+}
+
+impl_count_uint!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,119 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+/// Throughput counters attached to a single benchmark, rendered for JSON
+/// output.
+///
+/// Only counters that were actually measured are present; absent fields are
+/// omitted from the emitted object entirely (rather than serialized as
+/// `null`), matching libtest's style of lean, additive JSON events.
+#[derive(Default)]
+pub(crate) struct JsonCounters {
+    pub bytes: Option<u128>,
+    pub chars: Option<u128>,
+    pub items: Option<u128>,
+    pub cycles: Option<u128>,
+}
+
+/// A single `"bench"` event, emitted once per completed benchmark.
+///
+/// This mirrors libtest's JSON formatter: one line-delimited object per
+/// benchmark, carrying the same point estimates shown in the pretty table.
+pub(crate) struct JsonBenchEvent<'a> {
+    pub name: &'a str,
+    pub median_ns: f64,
+    pub deviation_ns: f64,
+    pub samples: u32,
+    pub counters: JsonCounters,
+}
+
+/// The final `"summary"` event, emitted once after all benchmarks have run.
+pub(crate) struct JsonSummaryEvent {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// Writes line-delimited JSON events to a sink, one object per line.
+///
+/// Each call to [`JsonWriter::bench`] or [`JsonWriter::summary`] emits
+/// exactly one line, so downstream tools can stream and parse Divan's output
+/// incrementally instead of waiting for the run to finish.
+pub(crate) struct JsonWriter<W> {
+    out: W,
+}
+
+impl<W: Write> JsonWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+
+    pub fn bench(&mut self, event: &JsonBenchEvent) -> io::Result<()> {
+        let mut line = String::new();
+
+        write!(
+            line,
+            r#"{{"type":"bench","name":{},"median":{},"deviation":{},"samples":{}"#,
+            json_string(event.name),
+            event.median_ns,
+            event.deviation_ns,
+            event.samples,
+        )
+        .unwrap();
+
+        let JsonCounters { bytes, chars, items, cycles } = event.counters;
+        if bytes.is_some() || chars.is_some() || items.is_some() || cycles.is_some() {
+            write!(line, r#","counters":{{"#).unwrap();
+
+            let mut wrote_any = false;
+            let mut write_counter = |line: &mut String, key: &str, value: Option<u128>| {
+                if let Some(value) = value {
+                    if wrote_any {
+                        line.push(',');
+                    }
+                    write!(line, r#""{key}":{value}"#).unwrap();
+                    wrote_any = true;
+                }
+            };
+
+            write_counter(&mut line, "bytes", bytes);
+            write_counter(&mut line, "chars", chars);
+            write_counter(&mut line, "items", items);
+            write_counter(&mut line, "cycles", cycles);
+
+            line.push('}');
+        }
+
+        line.push('}');
+
+        writeln!(self.out, "{line}")
+    }
+
+    pub fn summary(&mut self, event: &JsonSummaryEvent) -> io::Result<()> {
+        writeln!(
+            self.out,
+            r#"{{"type":"summary","total":{},"passed":{},"failed":{}}}"#,
+            event.total, event.passed, event.failed,
+        )
+    }
+}
+
+/// Escapes and quotes a string for embedding in a JSON document.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
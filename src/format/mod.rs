@@ -0,0 +1,10 @@
+//! Output formatting for benchmark results.
+//!
+//! Divan supports multiple [`FormatStyle`](crate::config::FormatStyle)s:
+//! - `pretty`: a human-readable table, grouped by benchmark hierarchy.
+//! - `terse`: a condensed single-line-per-benchmark form.
+//! - `json`: line-delimited JSON events, for machine consumption.
+
+mod json;
+
+pub(crate) use json::{JsonBenchEvent, JsonCounters, JsonSummaryEvent, JsonWriter};
@@ -0,0 +1,16 @@
+//! Divan: a statistically-comfy benchmarking library.
+
+mod baseline;
+mod bench_command;
+mod bencher;
+pub(crate) mod cli;
+pub mod config;
+pub mod counter;
+mod format;
+mod report;
+mod stats;
+pub mod time;
+
+pub use bencher::Bencher;
+pub use config::{FormatStyle, SortingAttr};
+pub use time::TimerKind;
@@ -0,0 +1,225 @@
+//! The run loop's reporting step.
+//!
+//! Every benchmark's collected samples pass through here: outliers are
+//! optionally excluded, a bootstrap confidence interval is computed for the
+//! median, the result is compared against a loaded baseline if one was
+//! requested, and finally the whole thing is dispatched to the configured
+//! [`FormatStyle`].
+
+use std::io::{self, Write};
+
+use crate::{
+    baseline::{self, Baseline, BaselineWriter},
+    config::FormatStyle,
+    counter::{CounterCollection, KnownCounterKind},
+    format::{JsonBenchEvent, JsonCounters, JsonSummaryEvent, JsonWriter},
+    stats::{self, ConfidenceInterval},
+};
+
+/// One benchmark's collected per-sample timings, in nanoseconds, ready for
+/// analysis and reporting.
+pub(crate) struct BenchReport {
+    pub name: String,
+    pub samples_ns: Vec<f64>,
+    pub counters: CounterCollection,
+
+    /// Average cycles-per-iteration, when [`CyclesCount`](crate::counter::CyclesCount)
+    /// was used and at least one sample yielded a cycle reading (see
+    /// [`crate::bencher::SampleTiming::cycles`]).
+    pub avg_cycles_per_iter: Option<f64>,
+}
+
+/// The result of analyzing one benchmark's samples.
+pub(crate) struct BenchAnalysis {
+    pub name: String,
+    pub median_ns: ConfidenceInterval,
+    pub mean_ns: ConfidenceInterval,
+    pub outliers: stats::OutlierCounts,
+    pub baseline_delta: Option<baseline::BaselineDelta>,
+}
+
+/// Configuration for a reporting pass, gathered from the parsed CLI
+/// arguments (`--format`, `--exclude-outliers`, `--bootstrap-resamples`,
+/// `--baseline`).
+pub(crate) struct RunOptions<'a> {
+    pub format: FormatStyle,
+    pub exclude_outliers: bool,
+    pub bootstrap_resamples: usize,
+    pub baseline: Option<&'a Baseline>,
+    pub noise_threshold: f64,
+}
+
+/// Analyzes `reports` and writes the result to `out` in `options.format`,
+/// returning the per-benchmark analyses so the caller can save a new
+/// baseline from them via [`save_baseline`].
+pub(crate) fn run(
+    out: &mut impl Write,
+    reports: &[BenchReport],
+    options: &RunOptions,
+) -> io::Result<Vec<BenchAnalysis>> {
+    let mut analyses = Vec::with_capacity(reports.len());
+    let mut resample_buf = Vec::new();
+
+    for report in reports {
+        let fences = stats::TukeyFences::compute(&report.samples_ns);
+        let outliers = fences.map(|f| f.count_outliers(&report.samples_ns)).unwrap_or_default();
+
+        let analyzed_samples: Vec<f64> = match (options.exclude_outliers, fences) {
+            (true, Some(fences)) => report
+                .samples_ns
+                .iter()
+                .copied()
+                .filter(|&sample| !fences.classify(sample).is_outlier())
+                .collect(),
+            _ => report.samples_ns.clone(),
+        };
+
+        let point_estimate =
+            ConfidenceInterval { estimate: analyzed_samples.first().copied().unwrap_or(0.0), lower: 0.0, upper: 0.0 };
+
+        // The bootstrap resamples the same collected samples for both
+        // statistics, so a fresh seed per statistic avoids the mean and
+        // median CIs being computed from identical resample draws. The
+        // offset is 2, not 1, since the PRNG forces the seed's low bit on.
+        let median_ns = stats::confidence_interval(
+            &analyzed_samples,
+            options.bootstrap_resamples,
+            &mut resample_buf,
+            fnv1a_seed(&report.name),
+            stats::median_in_place,
+        )
+        .unwrap_or(point_estimate);
+
+        let mean_ns = stats::confidence_interval(
+            &analyzed_samples,
+            options.bootstrap_resamples,
+            &mut resample_buf,
+            fnv1a_seed(&report.name).wrapping_add(2),
+            stats::mean,
+        )
+        .unwrap_or(point_estimate);
+
+        let baseline_delta = options.baseline.and_then(|baseline| baseline.median_ns(&report.name)).map(
+            |baseline_ns| baseline::compare(baseline_ns, median_ns.estimate, options.noise_threshold),
+        );
+
+        match options.format {
+            FormatStyle::Json => {
+                JsonWriter::new(&mut *out).bench(&JsonBenchEvent {
+                    name: &report.name,
+                    median_ns: median_ns.estimate,
+                    deviation_ns: (median_ns.upper - median_ns.lower) / 2.0,
+                    samples: analyzed_samples.len() as u32,
+                    counters: json_counters(&report.counters),
+                })?;
+            }
+            FormatStyle::Pretty | FormatStyle::Terse => {
+                print_text(out, options.format, report, &median_ns, &mean_ns, outliers, baseline_delta)?;
+            }
+        }
+
+        analyses.push(BenchAnalysis {
+            name: report.name.clone(),
+            median_ns,
+            mean_ns,
+            outliers,
+            baseline_delta,
+        });
+    }
+
+    if options.format == FormatStyle::Json {
+        JsonWriter::new(&mut *out)
+            .summary(&JsonSummaryEvent { total: reports.len(), passed: reports.len(), failed: 0 })?;
+    }
+
+    Ok(analyses)
+}
+
+/// Saves `analyses`' medians as a new baseline named `name` under `dir`, for
+/// a run invoked with `--save-baseline NAME`.
+pub(crate) fn save_baseline(
+    dir: &std::path::Path,
+    name: &str,
+    analyses: &[BenchAnalysis],
+) -> io::Result<()> {
+    let mut writer = BaselineWriter::default();
+    for analysis in analyses {
+        writer.record(&analysis.name, analysis.median_ns.estimate);
+    }
+    writer.save(dir, name)
+}
+
+fn json_counters(counters: &CounterCollection) -> JsonCounters {
+    JsonCounters {
+        bytes: counters.get(KnownCounterKind::Bytes).map(|c| c.count),
+        chars: counters.get(KnownCounterKind::Chars).map(|c| c.count),
+        items: counters.get(KnownCounterKind::Items).map(|c| c.count),
+        cycles: counters.get(KnownCounterKind::Cycles).map(|c| c.count),
+    }
+}
+
+fn print_text(
+    out: &mut impl Write,
+    format: FormatStyle,
+    report: &BenchReport,
+    median: &ConfidenceInterval,
+    mean: &ConfidenceInterval,
+    outliers: stats::OutlierCounts,
+    baseline_delta: Option<baseline::BaselineDelta>,
+) -> io::Result<()> {
+    match format {
+        FormatStyle::Terse => write!(out, "{:<32} {}", report.name, format_duration(median.estimate))?,
+        _ => write!(
+            out,
+            "{:<32} {} [{} .. {}]  (mean {} [{} .. {}])",
+            report.name,
+            format_duration(median.estimate),
+            format_duration(median.lower),
+            format_duration(median.upper),
+            format_duration(mean.estimate),
+            format_duration(mean.lower),
+            format_duration(mean.upper),
+        )?,
+    }
+
+    if outliers.total() > 0 {
+        write!(out, "  {}", stats::format_outlier_summary(outliers, report.samples_ns.len()))?;
+    }
+
+    if let Some(delta) = baseline_delta {
+        let sign = if delta.fraction >= 0.0 { "+" } else { "" };
+        let label = match delta.change {
+            baseline::BaselineChange::Improvement => " improved",
+            baseline::BaselineChange::Regression => " regressed",
+            baseline::BaselineChange::NoChange => "",
+        };
+        write!(out, "  ({sign}{:.1}%{label})", delta.fraction * 100.0)?;
+    }
+
+    if let Some(cycles_per_op) = report.avg_cycles_per_iter {
+        write!(out, "  {cycles_per_op:.1} {}", KnownCounterKind::Cycles.throughput_unit())?;
+    }
+
+    writeln!(out)
+}
+
+fn format_duration(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} \u{b5}s", ns / 1_000.0)
+    } else {
+        format!("{ns:.2} ns")
+    }
+}
+
+/// Deterministic per-benchmark seed for the bootstrap's PRNG, so repeated
+/// runs of the same benchmark produce the same confidence interval.
+fn fnv1a_seed(name: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in name.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
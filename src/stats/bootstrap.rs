@@ -0,0 +1,141 @@
+//! Bootstrap resampling for confidence intervals.
+//!
+//! Given a set of per-sample timings, this repeatedly draws resamples (with
+//! replacement) of the same size as the original set, computes a statistic
+//! over each resample, and reports the 2.5th/97.5th percentiles of those
+//! statistics as a 95% confidence interval. This is the same approach used
+//! by Criterion and libtest's internal stats module.
+
+/// Default number of bootstrap resamples.
+pub(crate) const DEFAULT_RESAMPLES: usize = 100_000;
+
+/// A point estimate alongside its 95% confidence interval.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ConfidenceInterval {
+    pub estimate: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// A simple xorshift PRNG, sufficient for resample indices and avoiding a
+/// dependency on a full `rand` crate.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Avoid an all-zero state, which xorshift can't escape.
+        Self(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn gen_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+/// Computes a bootstrap confidence interval for `statistic` over `samples`.
+///
+/// Returns `None` if there are fewer than 2 samples, since a meaningful
+/// interval requires at least that much variation to resample from.
+///
+/// `resamples` controls how many bootstrap iterations to run; higher values
+/// produce a smoother, more stable interval at the cost of more compute.
+/// `resample_buf` is reused across all `resamples` iterations: each
+/// iteration overwrites it in place with a fresh draw rather than
+/// allocating a new `Vec`, and `statistic` receives it as `&mut [f64]` so
+/// allocation-free statistics (like [`median_in_place`]) can sort it in
+/// place too, so the number of allocations per call doesn't grow with `R`.
+pub(crate) fn confidence_interval(
+    samples: &[f64],
+    resamples: usize,
+    resample_buf: &mut Vec<f64>,
+    seed: u64,
+    statistic: impl Fn(&mut [f64]) -> f64,
+) -> Option<ConfidenceInterval> {
+    if samples.len() < 2 {
+        return None;
+    }
+
+    let estimate = statistic(&mut samples.to_vec());
+
+    let mut rng = Rng::new(seed);
+    resample_buf.clear();
+    resample_buf.resize(samples.len(), 0.0);
+
+    let mut resample_statistics = Vec::with_capacity(resamples);
+
+    for _ in 0..resamples {
+        for slot in resample_buf.iter_mut() {
+            *slot = samples[rng.gen_index(samples.len())];
+        }
+        resample_statistics.push(statistic(resample_buf));
+    }
+
+    resample_statistics.sort_unstable_by(f64::total_cmp);
+
+    let lower_idx = ((resamples as f64) * 0.025) as usize;
+    let upper_idx = (((resamples as f64) * 0.975) as usize).min(resamples - 1);
+
+    Some(ConfidenceInterval {
+        estimate,
+        lower: resample_statistics[lower_idx],
+        upper: resample_statistics[upper_idx],
+    })
+}
+
+/// Arithmetic mean of `samples`. Doesn't need to mutate the buffer, but takes
+/// `&mut [f64]` to share a signature with [`median_in_place`].
+pub(crate) fn mean(samples: &mut [f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+/// Median of `samples`, sorting it in place rather than allocating a sorted
+/// copy. Safe to call with the same buffer on every resample, since each
+/// resample fully overwrites it before this runs.
+pub(crate) fn median_in_place(samples: &mut [f64]) -> f64 {
+    samples.sort_unstable_by(f64::total_cmp);
+
+    let mid = samples.len() / 2;
+    if samples.len() % 2 == 0 {
+        (samples[mid - 1] + samples[mid]) / 2.0
+    } else {
+        samples[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn degenerate_sample_counts_skip_ci() {
+        assert!(confidence_interval(&[], 100, &mut Vec::new(), 1, mean).is_none());
+        assert!(confidence_interval(&[1.0], 100, &mut Vec::new(), 1, mean).is_none());
+    }
+
+    #[test]
+    fn ci_contains_point_estimate() {
+        let samples: Vec<f64> = (1..=100).map(|n| n as f64).collect();
+        let ci = confidence_interval(&samples, 10_000, &mut Vec::new(), 42, mean).unwrap();
+
+        assert!(ci.lower <= ci.estimate);
+        assert!(ci.estimate <= ci.upper);
+    }
+
+    #[test]
+    fn median_in_place_matches_naive_median() {
+        let mut samples = [5.0, 1.0, 3.0, 2.0, 4.0];
+        assert_eq!(median_in_place(&mut samples), 3.0);
+
+        let mut samples = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(median_in_place(&mut samples), 2.5);
+    }
+}
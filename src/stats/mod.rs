@@ -0,0 +1,7 @@
+//! Statistical analysis of collected benchmark samples.
+
+mod bootstrap;
+mod outliers;
+
+pub(crate) use bootstrap::{confidence_interval, mean, median_in_place, ConfidenceInterval, DEFAULT_RESAMPLES};
+pub(crate) use outliers::{format_summary as format_outlier_summary, OutlierCounts, TukeyFences};
@@ -0,0 +1,148 @@
+//! Tukey-fence outlier classification for collected samples.
+//!
+//! After collecting per-sample times, this computes the first and third
+//! quartiles and flags samples that fall far outside that range, following
+//! the same convention as libtest's stats module:
+//! - mild outliers lie beyond `Q1 - 1.5*IQR` or `Q3 + 1.5*IQR`
+//! - severe outliers lie beyond `Q1 - 3*IQR` or `Q3 + 3*IQR`
+
+/// Classification of a single sample relative to the Tukey fences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum OutlierKind {
+    None,
+    MildLow,
+    MildHigh,
+    SevereLow,
+    SevereHigh,
+}
+
+impl OutlierKind {
+    pub fn is_outlier(self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    pub fn is_severe(self) -> bool {
+        matches!(self, Self::SevereLow | Self::SevereHigh)
+    }
+}
+
+/// Counts of mild and severe outliers found within a sample set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct OutlierCounts {
+    pub mild: usize,
+    pub severe: usize,
+}
+
+impl OutlierCounts {
+    pub fn total(self) -> usize {
+        self.mild + self.severe
+    }
+}
+
+/// The Tukey fences computed from a sample set's quartiles.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TukeyFences {
+    mild_low: f64,
+    mild_high: f64,
+    severe_low: f64,
+    severe_high: f64,
+}
+
+impl TukeyFences {
+    /// Computes fences from `samples`, which need not be sorted.
+    ///
+    /// Returns `None` if there are too few samples to compute quartiles.
+    pub fn compute(samples: &[f64]) -> Option<Self> {
+        if samples.len() < 4 {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let iqr = q3 - q1;
+
+        Some(Self {
+            mild_low: q1 - 1.5 * iqr,
+            mild_high: q3 + 1.5 * iqr,
+            severe_low: q1 - 3.0 * iqr,
+            severe_high: q3 + 3.0 * iqr,
+        })
+    }
+
+    pub fn classify(&self, value: f64) -> OutlierKind {
+        if value < self.severe_low {
+            OutlierKind::SevereLow
+        } else if value > self.severe_high {
+            OutlierKind::SevereHigh
+        } else if value < self.mild_low {
+            OutlierKind::MildLow
+        } else if value > self.mild_high {
+            OutlierKind::MildHigh
+        } else {
+            OutlierKind::None
+        }
+    }
+
+    /// Classifies every sample and tallies the mild/severe counts.
+    pub fn count_outliers(&self, samples: &[f64]) -> OutlierCounts {
+        let mut counts = OutlierCounts::default();
+
+        for &value in samples {
+            match self.classify(value) {
+                OutlierKind::MildLow | OutlierKind::MildHigh => counts.mild += 1,
+                OutlierKind::SevereLow | OutlierKind::SevereHigh => counts.severe += 1,
+                OutlierKind::None => {}
+            }
+        }
+
+        counts
+    }
+}
+
+/// Linear-interpolation percentile of an already-sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let idx = p * (sorted.len() - 1) as f64;
+    let lower = idx.floor() as usize;
+    let upper = idx.ceil() as usize;
+
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = idx - lower as f64;
+        sorted[lower] * (1.0 - frac) + sorted[upper] * frac
+    }
+}
+
+/// Formats an outlier summary like libtest's, e.g.
+/// `3 (2 mild, 1 severe) outliers among 100 samples`.
+pub(crate) fn format_summary(counts: OutlierCounts, total_samples: usize) -> String {
+    format!(
+        "{} ({} mild, {} severe) outliers among {} samples",
+        counts.total(),
+        counts.mild,
+        counts.severe,
+        total_samples,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_extreme_value_as_severe() {
+        let samples: Vec<f64> = (1..=20).map(|n| n as f64).collect();
+        let fences = TukeyFences::compute(&samples).unwrap();
+
+        assert_eq!(fences.classify(1000.0), OutlierKind::SevereHigh);
+        assert_eq!(fences.classify(10.0), OutlierKind::None);
+    }
+
+    #[test]
+    fn too_few_samples_skips_fences() {
+        assert!(TukeyFences::compute(&[1.0, 2.0]).is_none());
+    }
+}
@@ -0,0 +1,67 @@
+//! Timers used to measure benchmark samples.
+
+use std::time::{Duration, Instant};
+
+/// Which timer Divan reads around each sample, set via `--timer`. Wall time
+/// is measured with the OS clock under either kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimerKind {
+    /// The operating system's monotonic clock. This is the default and
+    /// works everywhere.
+    #[default]
+    Os,
+
+    /// The CPU's timestamp counter, read directly via `RDTSC` around each
+    /// sample in addition to the OS clock.
+    ///
+    /// Paired with [`CyclesCount`](crate::counter::CyclesCount), this gives
+    /// a frequency-independent throughput metric. Only available on
+    /// `x86`/`x86_64`.
+    Tsc,
+}
+
+/// Reads the CPU's timestamp counter, if available on this target.
+#[inline]
+pub(crate) fn read_tsc() -> Option<u64> {
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: `_rdtsc` is available on all x86_64 CPUs.
+    return Some(unsafe { std::arch::x86_64::_rdtsc() });
+
+    #[cfg(target_arch = "x86")]
+    // SAFETY: `_rdtsc` is available on all x86 CPUs with SSE2.
+    return Some(unsafe { std::arch::x86::_rdtsc() });
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    return None;
+}
+
+/// Estimates the TSC frequency, in cycles per second, by comparing a TSC
+/// delta against a wall-clock duration over a short calibration spin.
+///
+/// Returns `None` if the TSC isn't available on this target.
+pub(crate) fn estimate_tsc_frequency() -> Option<f64> {
+    let start_tsc = read_tsc()?;
+    let start = Instant::now();
+
+    // Long enough that OS clock resolution doesn't dominate, short enough
+    // to not slow down startup noticeably.
+    while start.elapsed() < Duration::from_millis(10) {
+        std::hint::spin_loop();
+    }
+
+    let end_tsc = read_tsc()?;
+    let elapsed = start.elapsed();
+
+    Some((end_tsc - start_tsc) as f64 / elapsed.as_secs_f64())
+}
+
+/// Converts a wall-clock duration to an equivalent cycle count using an
+/// estimated TSC `frequency_hz`.
+///
+/// Used as a fallback for [`CyclesCount`](crate::counter::CyclesCount) when
+/// `--timer tsc` isn't active, so the counter isn't simply dropped — the
+/// reported figure is only as accurate as the frequency estimate, unlike a
+/// direct TSC read around the benchmarked closure.
+pub(crate) fn ns_to_cycles(ns: f64, frequency_hz: f64) -> u64 {
+    (ns * frequency_hz / 1_000_000_000.0).round() as u64
+}